@@ -1,30 +1,144 @@
+use std::cmp::Ordering;
 use std::error::Error as StdError;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::panic::Location;
 use std::sync::Mutex;
 
 pub type Error = Box<dyn StdError + Send + Sync>;
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// Wraps `error` with an additional `msg` and the source location of the `context`/
+/// `with_context` call that created this layer.
+///
+/// The location is available through [`Context::location`] and is shown in the `{:?}`
+/// (`Debug`) rendering of this layer, but it is *not* part of `Display` (which forwards
+/// exactly `msg`, as before location tracking was added) and it is ignored by `Eq`/`Ord`/
+/// `Hash`: two `Context`s built from the same `msg`/`error` at different call sites still
+/// compare and hash equal.
+///
+/// Chaining `.context()` straight onto an existing `Context` (`err.context("a").context("b")`)
+/// also carries the inner layer's location along, so it still shows up in the `{:?}` rendering
+/// instead of being lost the moment that layer is handed to `write_causes` as a type-erased
+/// `&dyn StdError`. This relies on `self`'s concrete type still being known at the call site,
+/// so it only helps for chains built directly like that; once a `Context` has been boxed into
+/// a `dyn StdError` and passed through unrelated generic code, there's no way back to its
+/// concrete type to recover this (see `nested_locations` below).
 pub struct Context<M, E> {
     msg: M,
     error: E,
+    location: &'static Location<'static>,
+    /// Locations of any `Context` layers wrapped up in `error`, outermost first, collected by
+    /// [`Context::context`] while they were still concrete. Empty unless this layer was itself
+    /// built by that method, i.e. on top of an `error` that is a `Context`.
+    nested_locations: Vec<&'static Location<'static>>,
+}
+
+impl<M, E> Clone for Context<M, E>
+where
+    M: Clone,
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        Context {
+            msg: self.msg.clone(),
+            error: self.error.clone(),
+            location: self.location,
+            nested_locations: self.nested_locations.clone(),
+        }
+    }
 }
 
 impl<M, E> Context<M, E> {
+    #[track_caller]
     pub fn new(msg: M, error: E) -> Self {
         Context {
             msg,
             error,
+            location: Location::caller(),
+            nested_locations: Vec::new(),
+        }
+    }
+
+    /// The source location of the `context`/`with_context` call that created this layer.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Wraps this `Context` with another layer, same as [`ErrorExt::context`]. Preferred over
+    /// that blanket impl by ordinary method resolution whenever the receiver's concrete type
+    /// is (still) `Context`, which lets it also carry `self`'s own location (and any it
+    /// collected in turn) forward, restoring it in the `{:?}` rendering instead of losing it
+    /// right here to type erasure.
+    #[track_caller]
+    pub fn context<M2: Display + Debug>(self, msg: M2) -> Context<M2, Self> {
+        let mut nested_locations = Vec::with_capacity(1 + self.nested_locations.len());
+        nested_locations.push(self.location);
+        nested_locations.extend_from_slice(&self.nested_locations);
+        Context {
+            msg,
+            location: Location::caller(),
+            nested_locations,
+            error: self,
+        }
+    }
+
+    /// Wraps this `Context` in a [`SyncError`], same as [`ErrorExt::sync_err`], but — for the
+    /// same reason as [`Context::context`] above — also carries this layer's own location (and
+    /// any nested ones) along, so `SyncError`'s `{:?}` rendering doesn't drop them.
+    pub fn sync_err(self) -> SyncError<Self> {
+        let mut nested_locations = Vec::with_capacity(1 + self.nested_locations.len());
+        nested_locations.push(self.location);
+        nested_locations.extend_from_slice(&self.nested_locations);
+        SyncError {
+            error: Mutex::new(self),
+            nested_locations,
+        }
+    }
+}
+
+impl<M: PartialEq, E: PartialEq> PartialEq for Context<M, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.msg == other.msg && self.error == other.error
+    }
+}
+
+impl<M: Eq, E: Eq> Eq for Context<M, E> {}
+
+impl<M: PartialOrd, E: PartialOrd> PartialOrd for Context<M, E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.msg.partial_cmp(&other.msg) {
+            Some(Ordering::Equal) => self.error.partial_cmp(&other.error),
+            ord => ord,
         }
     }
 }
 
+impl<M: Ord, E: Ord> Ord for Context<M, E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.msg.cmp(&other.msg).then_with(|| self.error.cmp(&other.error))
+    }
+}
+
+impl<M: Hash, E: Hash> Hash for Context<M, E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.msg.hash(state);
+        self.error.hash(state);
+    }
+}
+
 impl<M: Display, E> Display for Context<M, E> {
     fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
         self.msg.fmt(fmt)
     }
 }
 
+impl<M: Display, E: StdError + 'static> Debug for Context<M, E> {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(fmt, "{} (at {})", self.msg, self.location)?;
+        write_causes(&self.error, &self.nested_locations, fmt)
+    }
+}
+
 impl<M: Debug + Display, E: Debug + StdError + 'static> StdError for Context<M, E> {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         Some(&self.error)
@@ -32,6 +146,7 @@ impl<M: Debug + Display, E: Debug + StdError + 'static> StdError for Context<M,
 }
 
 pub trait ErrorExt {
+    #[track_caller]
     fn context<M: Display + Debug>(self, msg: M) -> Context<M, Self>
     where
         Self: Sized;
@@ -39,10 +154,35 @@ pub trait ErrorExt {
     where
         Self: Sized;
 
-    // TODO: Convenience functions like iter_causes?
+    /// Walks the `source()` chain starting at `self`.
+    ///
+    /// The first item yielded is `self`, followed by its cause, its cause's cause, and so
+    /// on. There's no protection against a degenerate cycle in a hand-rolled `source()`
+    /// impl; such an implementation would already violate the contract of `StdError` and is
+    /// expected not to happen in practice.
+    fn iter_chain(&self) -> Chain<'_>
+    where
+        Self: 'static;
+
+    /// The last error in the [`iter_chain`][ErrorExt::iter_chain], i.e. the one with no
+    /// further `source()`.
+    fn root_cause(&self) -> &(dyn StdError + 'static)
+    where
+        Self: 'static;
+
+    /// Searches the `source()` chain (including `self`) for the first error of type `T`.
+    fn find_context<T: StdError + 'static>(&self) -> Option<&T>
+    where
+        Self: 'static;
+
+    /// Whether the `source()` chain (including `self`) contains an error of type `T`.
+    fn is_caused_by<T: StdError + 'static>(&self) -> bool
+    where
+        Self: 'static;
 }
 
 impl<E: StdError> ErrorExt for E {
+    #[track_caller]
     fn context<M: Debug + Display>(self, msg: M) -> Context<M, Self>
     where
         Self: Sized
@@ -55,12 +195,84 @@ impl<E: StdError> ErrorExt for E {
     {
         SyncError::from(self)
     }
+    fn iter_chain(&self) -> Chain<'_>
+    where
+        Self: 'static
+    {
+        Chain { next: Some(self) }
+    }
+    fn root_cause(&self) -> &(dyn StdError + 'static)
+    where
+        Self: 'static
+    {
+        self.iter_chain()
+            .last()
+            .expect("iter_chain always yields at least self")
+    }
+    fn find_context<T: StdError + 'static>(&self) -> Option<&T>
+    where
+        Self: 'static
+    {
+        self.iter_chain().find_map(|e| e.downcast_ref::<T>())
+    }
+    fn is_caused_by<T: StdError + 'static>(&self) -> bool
+    where
+        Self: 'static
+    {
+        self.find_context::<T>().is_some()
+    }
+}
+
+/// An iterator over an error and its causes, as returned by [`ErrorExt::iter_chain`].
+pub struct Chain<'a> {
+    next: Option<&'a (dyn StdError + 'static)>,
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn StdError + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+/// Writes a `Caused by:` section listing `error` and everything in its `source()` chain,
+/// anyhow-style. Used by the pretty `Debug` impls of [`Context`], [`SyncError`] and
+/// [`Aggregate`].
+///
+/// Each entry is rendered with `Display`, not `Debug`: `Context`'s own `Debug` impl recurses
+/// into this very function, so using `Debug` here would print a nested, duplicated
+/// "Caused by:" section for every `Context` layer instead of one flat list. That means a
+/// `Context` entry's own call-site location isn't reachable through its `Display` impl or
+/// through the type-erased `&dyn StdError` chain here, so callers that have it handy from
+/// building the chain themselves (see [`Context::context`]) pass it in via `locations`
+/// instead: `locations[i]`, when present, is entry `i`'s location.
+fn write_causes(
+    error: &(dyn StdError + 'static),
+    locations: &[&'static Location<'static>],
+    fmt: &mut Formatter,
+) -> FmtResult {
+    writeln!(fmt)?;
+    writeln!(fmt)?;
+    write!(fmt, "Caused by:")?;
+    for (i, cause) in (Chain { next: Some(error) }).enumerate() {
+        writeln!(fmt)?;
+        write!(fmt, "    {}: {}", i, cause)?;
+        if let Some(location) = locations.get(i) {
+            write!(fmt, " (at {})", location)?;
+        }
+    }
+    Ok(())
 }
 
 pub trait ResultExt: Sized {
     type Err;
     type Ok;
+    #[track_caller]
     fn context<M: Display + Debug>(self, msg: M) -> Result<Self::Ok, Context<M, Self::Err>>;
+    #[track_caller]
     fn with_context<M: Display + Debug, F: FnOnce() -> M>(self, f: F)
         -> Result<Self::Ok, Context<M, Self::Err>>;
     fn sync_err(self) -> Result<Self::Ok, SyncError<Self::Err>>;
@@ -69,35 +281,167 @@ pub trait ResultExt: Sized {
 impl<T, E: StdError + Send + Sync + 'static> ResultExt for Result<T, E> {
     type Err = E;
     type Ok = T;
+    #[track_caller]
     fn context<M: Display + Debug>(self, msg: M) -> Result<Self::Ok, Context<M, Self::Err>> {
         self.with_context(|| msg)
     }
+    #[track_caller]
     fn with_context<M: Display + Debug, F: FnOnce() -> M>(self, f: F)
         -> Result<Self::Ok, Context<M, Self::Err>>
     {
-        self.map_err(|e| e.context(f()))
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.context(f())),
+        }
     }
     fn sync_err(self) -> Result<Self::Ok, SyncError<Self::Err>> {
         self.map_err(|e| e.sync_err())
     }
 }
 
-#[derive(Debug)]
-pub struct SyncError<E>(Mutex<E>);
+pub struct SyncError<E> {
+    error: Mutex<E>,
+    /// Same role as [`Context`]'s own field of the same name: locations of any `Context`
+    /// layers wrapped up in `error`, outermost first. Populated by [`Context::sync_err`] when
+    /// `error`'s concrete type was still known; empty when built through the generic
+    /// [`ErrorExt::sync_err`] blanket impl instead.
+    nested_locations: Vec<&'static Location<'static>>,
+}
 
 impl<E> From<E> for SyncError<E> {
     fn from(err: E) -> Self {
-        SyncError(Mutex::new(err))
+        SyncError {
+            error: Mutex::new(err),
+            nested_locations: Vec::new(),
+        }
     }
 }
 
 impl<E: Display> Display for SyncError<E> {
     fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
-        self.0.lock().unwrap().fmt(fmt)
+        self.error.lock().unwrap().fmt(fmt)
+    }
+}
+
+impl<E: StdError + 'static> Debug for SyncError<E> {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        let guard = self.error.lock().unwrap();
+        write!(fmt, "{}", *guard)?;
+        if let Some(location) = self.nested_locations.first() {
+            write!(fmt, " (at {})", location)?;
+        }
+        if let Some(cause) = guard.source() {
+            let rest = self.nested_locations.get(1..).unwrap_or(&[]);
+            write_causes(cause, rest, fmt)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: StdError + 'static> StdError for SyncError<E> { }
+
+/// An error that collects several other errors, for fan-out scenarios where more than one
+/// failure can happen independently (validating many inputs, joining concurrent tasks, ...).
+#[derive(Default)]
+pub struct Aggregate(Vec<Error>);
+
+impl Aggregate {
+    pub fn new() -> Self {
+        Aggregate(Vec::new())
+    }
+
+    pub fn push(&mut self, error: Error) {
+        self.0.push(error);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn first(&self) -> Option<&Error> {
+        self.0.first()
+    }
+
+    pub fn into_vec(self) -> Vec<Error> {
+        self.0
+    }
+}
+
+impl Display for Aggregate {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        match self.0.len() {
+            1 => write!(fmt, "1 error occurred"),
+            n => write!(fmt, "{} errors occurred", n),
+        }
+    }
+}
+
+impl Debug for Aggregate {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(fmt, "{}", self)?;
+        for (i, error) in self.0.iter().enumerate() {
+            writeln!(fmt)?;
+            writeln!(fmt)?;
+            // Render via `Display`, then walk this entry's own `source()` chain, same as
+            // `Context`'s and `SyncError`'s `Debug` impls do. `{:?}` can't be trusted here:
+            // a pushed error is just as likely to be a plain leaf `StdError` with a derived
+            // `Debug` (e.g. from `collect_errors`) as a hand-wrapped `Context`, and the
+            // derived struct representation is strictly worse than the error's own message.
+            write!(fmt, "{}: {}", i, error)?;
+            if let Some(cause) = error.source() {
+                // Unlike `Context`'s and `SyncError`'s own `Debug` impls, there's no location
+                // to pass here: `push`'s parameter is already the type-erased `Error`, so by
+                // the time an entry reaches `self.0` there's no concrete `Context` left to ask
+                // for one (the `Context::context`/`Context::sync_err` trick above needs the
+                // concrete type still in hand, which `Aggregate::push`'s signature doesn't
+                // give us).
+                write_causes(cause, &[], fmt)?;
+            }
+        }
+        Ok(())
     }
 }
 
-impl<E: StdError> StdError for SyncError<E> { }
+impl StdError for Aggregate {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.first().map(|e| &**e as &(dyn StdError + 'static))
+    }
+}
+
+/// Extension of [`Iterator`] for collecting an iterator of [`Result`]s while keeping every
+/// failure instead of stopping at the first one, via [`Aggregate`].
+pub trait IterExt: Iterator + Sized {
+    fn collect_errors<T, E>(self) -> Result<Vec<T>, Aggregate>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        E: StdError + Send + Sync + 'static;
+}
+
+impl<I: Iterator> IterExt for I {
+    fn collect_errors<T, E>(self) -> Result<Vec<T>, Aggregate>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+        E: StdError + Send + Sync + 'static,
+    {
+        let mut oks = Vec::new();
+        let mut errors = Aggregate::new();
+        for item in self {
+            match item {
+                Ok(v) => oks.push(v),
+                Err(e) => errors.push(Box::new(e)),
+            }
+        }
+        if errors.is_empty() {
+            Ok(oks)
+        } else {
+            Err(errors)
+        }
+    }
+}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 struct MsgErr<D>(D);
@@ -114,6 +458,45 @@ pub fn err_msg<D: Debug + Display>(msg: D) -> impl StdError {
     MsgErr(msg)
 }
 
+/// The error recorded by [`OptionExt`] for a `None`: there's no underlying cause to wrap,
+/// just the fact that a value was missing.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NoneError;
+
+impl Display for NoneError {
+    fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+        write!(fmt, "value was None")
+    }
+}
+
+impl StdError for NoneError {}
+
+/// Adds `context`/`with_context` to [`Option`], mirroring [`ResultExt`] for the case where
+/// there's no underlying error to wrap, just the fact that a value was missing.
+pub trait OptionExt<T> {
+    #[track_caller]
+    fn context<M: Display + Debug>(self, msg: M) -> Result<T, Context<M, NoneError>>;
+    #[track_caller]
+    fn with_context<M: Display + Debug, F: FnOnce() -> M>(self, f: F)
+        -> Result<T, Context<M, NoneError>>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
+    fn context<M: Display + Debug>(self, msg: M) -> Result<T, Context<M, NoneError>> {
+        self.with_context(|| msg)
+    }
+    #[track_caller]
+    fn with_context<M: Display + Debug, F: FnOnce() -> M>(self, f: F)
+        -> Result<T, Context<M, NoneError>>
+    {
+        match self {
+            Some(v) => Ok(v),
+            None => Err(Context::new(f(), NoneError)),
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! bail {
     ($e:expr) => {
@@ -142,3 +525,190 @@ macro_rules! ensure {
 macro_rules! format_err {
     ($($arg:tt)*) => { $crate::err_msg(format!($arg)) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Leaf;
+
+    impl Display for Leaf {
+        fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+            write!(fmt, "leaf failure")
+        }
+    }
+
+    impl StdError for Leaf {}
+
+    #[test]
+    fn track_caller_points_at_user_call_site_not_crate_internals() {
+        let expected = line!() + 1;
+        let err = Leaf.context("via ErrorExt");
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), expected);
+
+        let result: Result<i32, Leaf> = Err(Leaf);
+        let expected = line!() + 1;
+        let err = result.context("via ResultExt").unwrap_err();
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), expected);
+
+        let result: Result<i32, Leaf> = Err(Leaf);
+        let expected = line!() + 1;
+        let err = result.with_context(|| "via with_context").unwrap_err();
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), expected);
+
+        let missing: Option<i32> = None;
+        let expected = line!() + 1;
+        let err = missing.context("via OptionExt").unwrap_err();
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), expected);
+    }
+
+    #[test]
+    fn iter_chain_and_root_cause() {
+        let layer1 = Leaf.context("layer1");
+        let layer2 = layer1.context("layer2");
+
+        let messages: Vec<String> = layer2.iter_chain().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0], layer2.to_string());
+        assert_eq!(messages[2], "leaf failure");
+
+        assert_eq!(layer2.root_cause().to_string(), "leaf failure");
+    }
+
+    #[test]
+    fn pretty_debug_flattens_nested_context_chain_without_losing_locations() {
+        let layer1 = Leaf.context("layer1");
+        let layer1_location = layer1.location();
+        let layer2 = layer1.context("layer2");
+        let layer2_location = layer2.location();
+        let rendered = format!("{:?}", layer2);
+
+        // One flat "Caused by:" section, not one per nested `Context` layer.
+        assert_eq!(rendered.matches("Caused by:").count(), 1, "{}", rendered);
+        // The leaf must appear once, not once per layer that re-printed its own chain.
+        assert_eq!(rendered.matches("leaf failure").count(), 1, "{}", rendered);
+        // Both layers' locations show up, not just the outermost one.
+        assert_eq!(rendered.matches("(at ").count(), 2, "{}", rendered);
+
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], format!("layer2 (at {})", layer2_location));
+        assert_eq!(lines[2], "Caused by:");
+        assert_eq!(lines[3], format!("    0: layer1 (at {})", layer1_location));
+        assert_eq!(lines[4], "    1: leaf failure");
+    }
+
+    #[test]
+    fn sync_error_debug_does_not_duplicate_the_top_message() {
+        let err = Leaf.context("outer message").sync_err();
+        let rendered = format!("{:?}", err);
+
+        assert_eq!(rendered.matches("Caused by:").count(), 1, "{}", rendered);
+        assert_eq!(rendered.matches("outer message").count(), 1, "{}", rendered);
+        assert_eq!(rendered.matches("leaf failure").count(), 1, "{}", rendered);
+    }
+
+    #[test]
+    fn sync_error_debug_has_no_caused_by_for_a_leaf() {
+        let err = Leaf.sync_err();
+        let rendered = format!("{:?}", err);
+
+        assert_eq!(rendered, "leaf failure");
+    }
+
+    #[test]
+    fn sync_error_debug_preserves_location_of_wrapped_context() {
+        let ctx = Leaf.context("outer message");
+        let outer_location = ctx.location();
+        let err = ctx.sync_err();
+        let rendered = format!("{:?}", err);
+
+        assert_eq!(rendered.matches("Caused by:").count(), 1, "{}", rendered);
+        assert_eq!(
+            rendered,
+            format!(
+                "outer message (at {})\n\nCaused by:\n    0: leaf failure",
+                outer_location
+            )
+        );
+    }
+
+    #[derive(Debug)]
+    struct ParseFailure;
+
+    impl Display for ParseFailure {
+        fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
+            write!(fmt, "parse failure")
+        }
+    }
+
+    impl StdError for ParseFailure {}
+
+    #[test]
+    fn find_context_recovers_buried_type() {
+        let buried = ParseFailure.context("outer").context("even more outer");
+
+        let found = buried.find_context::<ParseFailure>();
+        assert!(found.is_some());
+        assert!(buried.is_caused_by::<ParseFailure>());
+        assert!(!buried.is_caused_by::<Leaf>());
+    }
+
+    #[test]
+    fn collect_errors_aggregates_failures() {
+        let results: Vec<Result<i32, Leaf>> = vec![Ok(1), Err(Leaf), Ok(2), Err(Leaf)];
+        let aggregate = results.into_iter().collect_errors().unwrap_err();
+
+        assert_eq!(aggregate.len(), 2);
+        assert_eq!(aggregate.to_string(), "2 errors occurred");
+    }
+
+    #[test]
+    fn collect_errors_passes_through_all_oks() {
+        let results: Vec<Result<i32, Leaf>> = vec![Ok(1), Ok(2), Ok(3)];
+        let oks = results.into_iter().collect_errors().unwrap();
+
+        assert_eq!(oks, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn aggregate_debug_does_not_duplicate_a_context_chain() {
+        let mut aggregate = Aggregate::new();
+        aggregate.push(Box::new(Leaf.context("layer1")));
+        let rendered = format!("{:?}", aggregate);
+
+        assert_eq!(rendered.matches("Caused by:").count(), 1, "{}", rendered);
+        assert_eq!(rendered.matches("leaf failure").count(), 1, "{}", rendered);
+    }
+
+    #[test]
+    fn aggregate_debug_renders_plain_leaf_errors_via_display() {
+        let results: Vec<Result<i32, Leaf>> = vec![Err(Leaf), Err(Leaf)];
+        let aggregate = results.into_iter().collect_errors().unwrap_err();
+        let rendered = format!("{:?}", aggregate);
+
+        assert_eq!(rendered.matches("Caused by:").count(), 0, "{}", rendered);
+        assert!(rendered.contains("0: leaf failure"), "{}", rendered);
+        assert!(rendered.contains("1: leaf failure"), "{}", rendered);
+        assert!(!rendered.contains("Leaf"), "{}", rendered);
+    }
+
+    #[test]
+    fn option_ext_context_on_none_reports_missing_value() {
+        let value: Option<i32> = None;
+        let err = value.context("missing key").unwrap_err();
+
+        assert_eq!(err.to_string(), "missing key");
+        assert_eq!(err.source().unwrap().to_string(), "value was None");
+    }
+
+    #[test]
+    fn option_ext_context_on_some_passes_through() {
+        let value = Some(42);
+        assert_eq!(value.context("missing key").unwrap(), 42);
+    }
+}